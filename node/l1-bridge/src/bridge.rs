@@ -0,0 +1,227 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+use std::thread::JoinHandle;
+
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use tokio::sync::watch;
+use vprogs_core_types::Checkpoint;
+
+use crate::{chain_block_metadata::ChainBlockMetadata, config::L1BridgeConfig, event::L1Event, worker::Worker};
+
+/// Snapshot of the virtual chain's finalization boundary and tip.
+#[derive(Clone, Debug, Default)]
+pub struct TipPosition {
+    root: Checkpoint<ChainBlockMetadata>,
+    tip: Checkpoint<ChainBlockMetadata>,
+}
+
+impl TipPosition {
+    /// Creates a snapshot from the current root and tip.
+    pub(crate) fn new(root: Checkpoint<ChainBlockMetadata>, tip: Checkpoint<ChainBlockMetadata>) -> Self {
+        Self { root, tip }
+    }
+
+    /// Returns the finalization boundary.
+    pub fn root(&self) -> &Checkpoint<ChainBlockMetadata> {
+        &self.root
+    }
+
+    /// Returns the current tip.
+    pub fn tip(&self) -> &Checkpoint<ChainBlockMetadata> {
+        &self.tip
+    }
+}
+
+pub(crate) type PositionSender = watch::Sender<TipPosition>;
+pub(crate) type Subscribers = Arc<Mutex<Vec<Sender<L1Event>>>>;
+
+/// Event-driven handle to a background worker connected to the L1 network.
+///
+/// Events are delivered through a lock-free queue drained with [`pop`](Self::pop),
+/// [`wait_and_pop`](Self::wait_and_pop), or [`drain`](Self::drain). Use
+/// [`subscribe`](Self::subscribe) for additional independent consumers, or
+/// [`tip_watcher`](Self::tip_watcher) to observe the current position without draining events.
+pub struct L1Bridge {
+    events: Receiver<L1Event>,
+    subscribers: Subscribers,
+    position: watch::Receiver<TipPosition>,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl L1Bridge {
+    /// Spawns the background worker and returns a handle to its event queue.
+    pub fn new(config: L1BridgeConfig) -> Self {
+        let subscribers: Subscribers = Arc::new(Mutex::new(Vec::new()));
+        let (position_tx, position_rx) = watch::channel(TipPosition::default());
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        let (sender, events) = unbounded();
+        subscribers.lock().expect("subscribers lock poisoned").push(sender);
+
+        let worker = Worker::new(config, subscribers.clone(), position_tx, shutdown.clone());
+        let handle = std::thread::spawn(move || worker.run());
+
+        Self { events, subscribers, position: position_rx, shutdown, handle: Some(handle) }
+    }
+
+    /// Pops the next queued event, or `None` if the queue is currently empty.
+    pub fn pop(&self) -> Option<L1Event> {
+        self.events.try_recv().ok()
+    }
+
+    /// Blocks until an event is available and pops it.
+    pub fn wait_and_pop(&self) -> L1Event {
+        self.events.recv().expect("worker thread dropped the event sender")
+    }
+
+    /// Drains and returns all currently queued events without blocking.
+    pub fn drain(&self) -> Vec<L1Event> {
+        self.events.try_iter().collect()
+    }
+
+    /// Registers an independent consumer of the event stream. The first event the returned
+    /// [`L1Subscription`] yields is always a [`L1Event::Reset`] carrying the current `root` and
+    /// `tip`, so a component attaching mid-stream establishes a correct baseline before seeing
+    /// incremental events.
+    pub fn subscribe(&self) -> L1Subscription {
+        let (sender, receiver) = unbounded();
+
+        // Hold `subscribers` across both the baseline snapshot and registration so they're
+        // atomic with respect to `Worker::publish` (which updates the position and broadcasts
+        // the corresponding event under the same lock): otherwise a position update could land
+        // between the snapshot and the push with its broadcast on either side of it, either
+        // gapping this subscriber (broadcast first) or replaying an already-baselined event to it
+        // (broadcast after).
+        let mut subscribers = self.subscribers.lock().expect("subscribers lock poisoned");
+        let position = self.position.borrow().clone();
+        let _ = sender.send(L1Event::Reset { root: position.root().clone(), tip: position.tip().clone() });
+        subscribers.push(sender);
+        drop(subscribers);
+
+        L1Subscription { receiver }
+    }
+
+    /// Returns a non-consuming watcher over the latest root/tip, updated on every
+    /// `ChainBlockAdded`, `Rollback`, and `Finalized`/root advance regardless of whether event
+    /// consumers are keeping up.
+    pub fn tip_watcher(&self) -> TipWatcher {
+        TipWatcher(self.position.clone())
+    }
+
+    /// Signals the worker to stop and waits for it to exit.
+    pub fn shutdown(mut self) {
+        self.shutdown.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Non-consuming watcher over the bridge's current `root`/`tip`, returned by
+/// [`L1Bridge::tip_watcher`]. Reading it never blocks on or drains the event queue.
+pub struct TipWatcher(watch::Receiver<TipPosition>);
+
+impl TipWatcher {
+    /// Returns the latest observed position.
+    pub fn get(&self) -> TipPosition {
+        self.0.borrow().clone()
+    }
+
+    /// Waits until the position changes, then returns the new value. Returns `None` if the
+    /// worker has shut down and will never update it again.
+    pub async fn changed(&mut self) -> Option<TipPosition> {
+        self.0.changed().await.ok()?;
+        Some(self.0.borrow().clone())
+    }
+}
+
+/// An independent consumer of the event stream, returned by [`L1Bridge::subscribe`]. Dropping it
+/// stops delivery and releases its queue.
+pub struct L1Subscription {
+    receiver: Receiver<L1Event>,
+}
+
+impl L1Subscription {
+    /// Pops the next queued event, or `None` if the queue is currently empty.
+    pub fn pop(&self) -> Option<L1Event> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Blocks until an event is available and pops it.
+    pub fn wait_and_pop(&self) -> L1Event {
+        self.receiver.recv().expect("worker thread dropped the event sender")
+    }
+
+    /// Drains and returns all currently queued events without blocking.
+    pub fn drain(&self) -> Vec<L1Event> {
+        self.receiver.try_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{event::RpcOptionalHeader, BlockHash};
+
+    fn bridge_with(subscribers: Subscribers, position: watch::Receiver<TipPosition>) -> L1Bridge {
+        let (_sender, events) = unbounded();
+        L1Bridge { events, subscribers, position, shutdown: Arc::new(AtomicBool::new(false)), handle: None }
+    }
+
+    /// Regression test for a race where `subscribe`'s `Reset` baseline could run ahead of the
+    /// broadcast it's supposed to make redundant: `Worker::publish` used to update the watched
+    /// position and broadcast the matching event as two separate locked sections, so a
+    /// `subscribe()` landing between them could read the new tip but register before the
+    /// broadcast, then receive that same block again as a `ChainBlockAdded` on top of an already
+    /// caught-up baseline.
+    #[test]
+    fn subscribe_baseline_is_never_followed_by_its_own_already_applied_block() {
+        let subscribers: Subscribers = Arc::new(Mutex::new(Vec::new()));
+        let (position_tx, position_rx) = watch::channel(TipPosition::default());
+        let worker = Worker::new(
+            L1BridgeConfig::default(),
+            subscribers.clone(),
+            position_tx,
+            Arc::new(AtomicBool::new(false)),
+        );
+        let bridge = bridge_with(subscribers, position_rx);
+
+        let publisher = std::thread::spawn(move || {
+            for index in 1..=500u64 {
+                let checkpoint = Checkpoint::new(index, ChainBlockMetadata::new(BlockHash::default(), index));
+                worker.publish(
+                    Checkpoint::default(),
+                    checkpoint.clone(),
+                    Some(L1Event::ChainBlockAdded {
+                        checkpoint,
+                        header: Box::new(RpcOptionalHeader::default()),
+                        accepted_transactions: Vec::new(),
+                    }),
+                );
+            }
+        });
+
+        for _ in 0..500 {
+            let subscription = bridge.subscribe();
+            let events = subscription.drain();
+            let baseline_tip = match events.first() {
+                Some(L1Event::Reset { tip, .. }) => tip.index(),
+                other => panic!("subscribe's first event must be a Reset baseline, got {other:?}"),
+            };
+            for event in &events[1..] {
+                if let L1Event::ChainBlockAdded { checkpoint, .. } = event {
+                    assert!(
+                        checkpoint.index() > baseline_tip,
+                        "subscriber baselined at tip {baseline_tip} was replayed already-applied block {}",
+                        checkpoint.index()
+                    );
+                }
+            }
+        }
+
+        publisher.join().expect("publisher thread panicked");
+    }
+}
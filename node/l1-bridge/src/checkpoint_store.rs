@@ -0,0 +1,32 @@
+use vprogs_core_types::Checkpoint;
+
+use crate::ChainBlockMetadata;
+
+/// Persists the virtual chain's `root`/`tip` so the bridge can resume automatically across
+/// restarts, instead of the caller threading them back through
+/// [`L1BridgeConfig::with_root`](crate::L1BridgeConfig::with_root)/
+/// [`with_tip`](crate::L1BridgeConfig::with_tip) by hand.
+///
+/// `ChainBlockMetadata` already derives Borsh, so an implementation can store the serialized
+/// `Checkpoint<ChainBlockMetadata>` directly.
+pub trait CheckpointStore: Send + Sync {
+    /// Persists the finalization boundary.
+    fn write_root(&self, checkpoint: &Checkpoint<ChainBlockMetadata>);
+
+    /// Persists the current tip.
+    fn write_tip(&self, checkpoint: &Checkpoint<ChainBlockMetadata>);
+
+    /// Loads the last persisted root and tip, if any have been written.
+    fn load(&self) -> (Option<Checkpoint<ChainBlockMetadata>>, Option<Checkpoint<ChainBlockMetadata>>);
+}
+
+/// Controls how eagerly the worker writes through to a configured [`CheckpointStore`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FlushPolicy {
+    /// Persist the tip on every `ChainBlockAdded`/`Rollback`, and the root whenever it advances.
+    #[default]
+    WriteThrough,
+    /// Buffer the tip in memory and only persist it once blocks are `Finalized` or a `Reset` is
+    /// emitted, along with the root whenever it advances.
+    OnFinalization,
+}
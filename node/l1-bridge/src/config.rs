@@ -1,11 +1,13 @@
+use std::fmt::{self, Debug, Formatter};
+use std::sync::Arc;
 use std::time::Duration;
 
 use vprogs_core_types::Checkpoint;
 
-use crate::{ChainBlockMetadata, ConnectStrategy, NetworkId, NetworkType};
+use crate::{checkpoint_store::CheckpointStore, ChainBlockMetadata, ConnectStrategy, FlushPolicy, NetworkId, NetworkType};
 
 /// Configuration for the L1 bridge.
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct L1BridgeConfig {
     /// WebSocket URL (e.g. `ws://localhost:17110`), or `None` to use the public resolver.
     pub url: Option<String>,
@@ -23,6 +25,12 @@ pub struct L1BridgeConfig {
     /// Reorg filter halving period. Observed reorg depths accumulate into a threshold that halves
     /// every period until it reaches zero. Set to `Duration::ZERO` to disable (default).
     pub reorg_filter_halving_period: Duration,
+    /// Store the worker writes `root`/`tip` to as they advance, or `None` to require the caller
+    /// to persist and supply `root`/`tip` manually. If set and the caller left `root` and `tip`
+    /// unset, the bridge loads them from the store on startup.
+    pub store: Option<Arc<dyn CheckpointStore>>,
+    /// How eagerly `root`/`tip` are written through to `store`.
+    pub flush_policy: FlushPolicy,
 }
 
 impl Default for L1BridgeConfig {
@@ -36,10 +44,28 @@ impl Default for L1BridgeConfig {
             root: None,                               // Start from the L1 pruning point.
             tip: None,
             reorg_filter_halving_period: Duration::ZERO, // Disabled by default.
+            store: None,                                 // Caller manages resume state manually.
+            flush_policy: FlushPolicy::default(),
         }
     }
 }
 
+impl Debug for L1BridgeConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("L1BridgeConfig")
+            .field("url", &self.url)
+            .field("network_id", &self.network_id)
+            .field("connect_timeout_ms", &self.connect_timeout_ms)
+            .field("connect_strategy", &self.connect_strategy)
+            .field("root", &self.root)
+            .field("tip", &self.tip)
+            .field("reorg_filter_halving_period", &self.reorg_filter_halving_period)
+            .field("store", &self.store.as_ref().map(|_| "Arc<dyn CheckpointStore>"))
+            .field("flush_policy", &self.flush_policy)
+            .finish()
+    }
+}
+
 impl L1BridgeConfig {
     /// Sets the WebSocket URL for the L1 node.
     pub fn with_url(mut self, url: impl Into<String>) -> Self {
@@ -89,4 +115,17 @@ impl L1BridgeConfig {
         self.reorg_filter_halving_period = period;
         self
     }
+
+    /// Sets the store the worker persists `root`/`tip` to. If `root`/`tip` are left unset, the
+    /// bridge loads its resume state from this store on startup.
+    pub fn with_store(mut self, store: Arc<dyn CheckpointStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
+    /// Sets how eagerly `root`/`tip` are written through to the configured store.
+    pub fn with_flush_policy(mut self, policy: FlushPolicy) -> Self {
+        self.flush_policy = policy;
+        self
+    }
 }
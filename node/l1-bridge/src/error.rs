@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::BlockHash;
+
+/// Errors produced while tracking the virtual chain.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// A rollback was requested past the finalization boundary.
+    #[error("rollback to index {target_index} is past root at index {root_index}")]
+    RollbackPastRoot {
+        /// Index the rollback targeted.
+        target_index: u64,
+        /// Index of the current finalization boundary.
+        root_index: u64,
+    },
+    /// A hash reported by the L1 node was not found while advancing the root.
+    #[error("hash {0} not found in virtual chain")]
+    HashNotFound(BlockHash),
+}
+
+pub(crate) type Result<T> = std::result::Result<T, Error>;
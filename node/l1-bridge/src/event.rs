@@ -26,9 +26,25 @@ pub enum L1Event {
         checkpoint: Checkpoint<ChainBlockMetadata>,
         /// Blue score difference between old and new tip.
         blue_score_depth: u64,
+        /// Checkpoints of the blocks that were unlinked, ordered tip-first (most recently added
+        /// block first) so consumers can pop their own state in LIFO order.
+        reverted: Vec<Checkpoint<ChainBlockMetadata>>,
     },
     /// Blocks up to this checkpoint are finalized and can be pruned.
     Finalized(Checkpoint<ChainBlockMetadata>),
+    /// Continuity could not be expressed as a single linear rollback; consumers must discard all
+    /// derived state and rebuild it from this position rather than reverse individual blocks.
+    ///
+    /// Emitted after the initial backfill between `root` and `tip` completes, after a
+    /// reconnection where the bridge cannot prove the previous tip is still on the selected
+    /// chain, and in place of a rollback deeper than the finalization boundary. Every new
+    /// subscription also receives one as its first event, carrying the current `root`/`tip`.
+    Reset {
+        /// Finalization boundary to rebuild derived state from.
+        root: Checkpoint<ChainBlockMetadata>,
+        /// Current tip to rebuild derived state up to.
+        tip: Checkpoint<ChainBlockMetadata>,
+    },
     /// The bridge encountered a fatal error and stopped.
     Fatal {
         /// What went wrong.
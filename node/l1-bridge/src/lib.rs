@@ -23,12 +23,19 @@
 //!         Some(L1Event::ChainBlockAdded { checkpoint, .. }) => {
 //!             println!("block {}", checkpoint.index());
 //!         }
-//!         Some(L1Event::Rollback { checkpoint, blue_score_depth }) => {
-//!             println!("rollback to {} (depth: {blue_score_depth})", checkpoint.index());
+//!         Some(L1Event::Rollback { checkpoint, blue_score_depth, reverted }) => {
+//!             println!(
+//!                 "rollback to {} (depth: {blue_score_depth}, {} blocks reverted)",
+//!                 checkpoint.index(),
+//!                 reverted.len()
+//!             );
 //!         }
 //!         Some(L1Event::Finalized(checkpoint)) => {
 //!             println!("finalized up to index {}", checkpoint.index());
 //!         }
+//!         Some(L1Event::Reset { root, tip }) => {
+//!             println!("resetting derived state to {}..={}", root.index(), tip.index());
+//!         }
 //!         Some(L1Event::Disconnected) => println!("disconnected"),
 //!         Some(L1Event::Fatal { reason }) => {
 //!             eprintln!("fatal: {reason}");
@@ -49,10 +56,24 @@
 //! To resume from a previously known chain position, pass both `root` and `tip`
 //! in the config. The bridge will backfill the chain between them on first
 //! connect before emitting new events.
+//!
+//! Alternatively, configure a [`CheckpointStore`] with [`L1BridgeConfig::with_store`] and leave
+//! `root`/`tip` unset: the bridge loads its resume state from the store on startup and the
+//! worker keeps it up to date automatically according to the configured [`FlushPolicy`].
+//!
+//! # Multiple consumers
+//!
+//! [`L1Bridge::subscribe`] returns an independent [`L1Subscription`] with its own queue. The
+//! first event it yields is always a [`L1Event::Reset`] carrying the current `root`/`tip`, so a
+//! component attaching mid-stream can establish a baseline without replaying history.
+//!
+//! Components that only need the current chain position — without draining events — should use
+//! [`L1Bridge::tip_watcher`] instead, which never blocks on a slow or absent event consumer.
 
 mod bridge;
 mod chain_block;
 mod chain_block_metadata;
+mod checkpoint_store;
 mod config;
 mod error;
 mod event;
@@ -60,8 +81,9 @@ mod reorg_filter;
 mod virtual_chain;
 mod worker;
 
-pub use bridge::L1Bridge;
+pub use bridge::{L1Bridge, L1Subscription, TipPosition, TipWatcher};
 pub use chain_block_metadata::ChainBlockMetadata;
+pub use checkpoint_store::{CheckpointStore, FlushPolicy};
 pub use config::L1BridgeConfig;
 pub use event::{Hash as BlockHash, L1Event, RpcOptionalHeader, RpcOptionalTransaction};
 pub use kaspa_consensus_core::network::{NetworkId, NetworkType};
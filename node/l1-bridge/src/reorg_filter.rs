@@ -0,0 +1,80 @@
+use std::time::{Duration, Instant};
+
+/// Suppresses small reorgs from being surfaced to consumers during periods of chain
+/// instability.
+///
+/// Every observed reorg's blue score depth raises a threshold; a reorg no deeper than the
+/// current threshold is filtered out. The threshold halves every `halving_period` so that once
+/// reorgs stop occurring, filtering relaxes back to nothing.
+pub(crate) struct ReorgFilter {
+    halving_period: Duration,
+    threshold: u64,
+    last_halving: Instant,
+}
+
+impl ReorgFilter {
+    /// Creates a filter with the given halving period. A `Duration::ZERO` period disables
+    /// filtering entirely.
+    pub(crate) fn new(halving_period: Duration) -> Self {
+        Self { halving_period, threshold: 0, last_halving: Instant::now() }
+    }
+
+    /// Records a reorg of the given blue score depth and returns whether it should be
+    /// suppressed (not emitted as an [`L1Event::Rollback`](crate::L1Event::Rollback)).
+    pub(crate) fn observe(&mut self, blue_score_depth: u64) -> bool {
+        if self.halving_period.is_zero() {
+            return false;
+        }
+        self.decay();
+        let suppress = blue_score_depth <= self.threshold;
+        self.threshold = self.threshold.max(blue_score_depth);
+        suppress
+    }
+
+    /// Halves the threshold for every full `halving_period` elapsed since the last decay.
+    fn decay(&mut self) {
+        if self.threshold == 0 {
+            self.last_halving = Instant::now();
+            return;
+        }
+        let elapsed = self.last_halving.elapsed();
+        let halvings = elapsed.as_nanos() / self.halving_period.as_nanos().max(1);
+        if halvings > 0 {
+            // u64 shifts panic in debug builds at exactly 64, so saturate the threshold to zero
+            // instead of shifting it away one bit at a time.
+            self.threshold = if halvings >= 64 { 0 } else { self.threshold >> halvings as u32 };
+            self.last_halving = Instant::now();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_when_halving_period_is_zero() {
+        let mut filter = ReorgFilter::new(Duration::ZERO);
+        assert!(!filter.observe(100));
+        assert!(!filter.observe(1));
+    }
+
+    #[test]
+    fn suppresses_reorgs_no_deeper_than_the_threshold() {
+        let mut filter = ReorgFilter::new(Duration::from_secs(60));
+        assert!(!filter.observe(5)); // First observation always passes and raises the threshold.
+        assert!(filter.observe(3)); // Shallower than the threshold: suppressed.
+        assert!(!filter.observe(10)); // Deeper than the threshold: passes and raises it further.
+    }
+
+    #[test]
+    fn decay_saturates_instead_of_panicking_after_many_halving_periods() {
+        let mut filter = ReorgFilter::new(Duration::from_nanos(1));
+        filter.observe(1_000_000);
+        std::thread::sleep(Duration::from_millis(1));
+
+        // Far more than 64 halving periods have elapsed since the first observation; decay must
+        // saturate the threshold to zero rather than shifting a u64 by >= 64 and panicking.
+        assert!(!filter.observe(1));
+    }
+}
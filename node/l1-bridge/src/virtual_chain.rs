@@ -41,27 +41,31 @@ impl VirtualChain {
         self.tip.checkpoint().clone()
     }
 
-    /// Rolls back `num_checkpoints` from the tip and returns the new tip checkpoint along with the
-    /// blue score depth (difference between old and new tip). Returns an error if the rollback
-    /// would go past the root.
+    /// Rolls back `num_checkpoints` from the tip and returns the new tip checkpoint, the blue
+    /// score depth (difference between old and new tip), and the checkpoints of the unlinked
+    /// blocks ordered tip-first (most recently added block first). Returns an error if the
+    /// rollback would go past the root.
     pub(crate) fn rollback(
         &mut self,
         num_checkpoints: u64,
-    ) -> Result<(Checkpoint<ChainBlockMetadata>, u64)> {
+    ) -> Result<(Checkpoint<ChainBlockMetadata>, u64, Vec<Checkpoint<ChainBlockMetadata>>)> {
         // Ensure we don't roll back past the finalization boundary.
         let target_index = self.tip.index().saturating_sub(num_checkpoints);
         if target_index < self.root.index() {
             return Err(Error::RollbackPastRoot { target_index, root_index: self.root.index() });
         }
 
-        // Calculate reorg depth and walk backwards, unlinking each node from its predecessor.
+        // Calculate reorg depth and walk backwards, unlinking each node from its predecessor and
+        // collecting each unlinked node's checkpoint tip-first.
         let old_blue_score = self.tip.metadata().blue_score();
+        let mut reverted = Vec::with_capacity(num_checkpoints as usize);
         for _ in 0..num_checkpoints {
+            reverted.push(self.tip.checkpoint().clone());
             self.tip = self.tip.rollback_tip();
         }
         let blue_score_depth = old_blue_score.saturating_sub(self.tip.metadata().blue_score());
 
-        Ok((self.tip.checkpoint().clone(), blue_score_depth))
+        Ok((self.tip.checkpoint().clone(), blue_score_depth, reverted))
     }
 
     /// Advances the root forward to the checkpoint matching `hash`, unlinking all nodes it passes.
@@ -100,3 +104,36 @@ impl Drop for VirtualChain {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(blue_score: u64) -> ChainBlockMetadata {
+        ChainBlockMetadata::new(BlockHash::default(), blue_score)
+    }
+
+    #[test]
+    fn rollback_collects_reverted_checkpoints_tip_first() {
+        let mut chain = VirtualChain::new(Checkpoint::new(0, metadata(0)));
+        chain.advance_tip(metadata(10));
+        chain.advance_tip(metadata(20));
+        chain.advance_tip(metadata(30));
+
+        let (new_tip, blue_score_depth, reverted) = chain.rollback(2).expect("rollback within root");
+
+        assert_eq!(new_tip.index(), 1);
+        assert_eq!(blue_score_depth, 20);
+        assert_eq!(reverted.iter().map(|checkpoint| checkpoint.index()).collect::<Vec<_>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn rollback_past_root_is_rejected() {
+        let mut chain = VirtualChain::new(Checkpoint::new(5, metadata(0)));
+        chain.advance_tip(metadata(10));
+        chain.advance_tip(metadata(20));
+
+        let error = chain.rollback(10).expect_err("rollback past root must fail");
+        assert!(matches!(error, Error::RollbackPastRoot { target_index: 0, root_index: 5 }));
+    }
+}
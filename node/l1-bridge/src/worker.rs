@@ -0,0 +1,365 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use vprogs_core_types::Checkpoint;
+
+use crate::{
+    bridge::{PositionSender, Subscribers, TipPosition},
+    chain_block_metadata::ChainBlockMetadata,
+    checkpoint_store::FlushPolicy,
+    config::L1BridgeConfig,
+    error::Error,
+    event::{L1Event, RpcOptionalHeader, RpcOptionalTransaction},
+    reorg_filter::ReorgFilter,
+    virtual_chain::VirtualChain,
+};
+
+/// Background worker that owns the wRPC connection and the [`VirtualChain`], fanning
+/// [`L1Event`]s out to every live subscriber as the selected parent chain advances.
+pub(crate) struct Worker {
+    config: L1BridgeConfig,
+    subscribers: Subscribers,
+    position: PositionSender,
+    shutdown: Arc<AtomicBool>,
+}
+
+impl Worker {
+    pub(crate) fn new(
+        config: L1BridgeConfig,
+        subscribers: Subscribers,
+        position: PositionSender,
+        shutdown: Arc<AtomicBool>,
+    ) -> Self {
+        Self { config, subscribers, position, shutdown }
+    }
+
+    /// Delivers `event` to every live subscriber, dropping any whose receiver has gone away.
+    fn broadcast(&self, event: L1Event) {
+        let mut subscribers = self.subscribers.lock().expect("subscribers lock poisoned");
+        subscribers.retain(|sender| sender.send(event.clone()).is_ok());
+    }
+
+    /// Updates the watched root/tip read by [`L1Bridge::subscribe`](crate::L1Bridge::subscribe)
+    /// and [`L1Bridge::tip_watcher`](crate::L1Bridge::tip_watcher), and broadcasts `event` (if
+    /// any) to every live subscriber — both under the same `subscribers` lock acquisition.
+    ///
+    /// This must happen as a single critical section rather than as two separate calls:
+    /// `subscribe` also snapshots the position under that lock to build its `Reset` baseline, so
+    /// if the position update and the broadcast were serialized independently, a `subscribe` in
+    /// between them could observe the new position but register before the matching broadcast,
+    /// causing its fresh subscriber to receive that event again on top of an already-current
+    /// baseline.
+    pub(crate) fn publish(
+        &self,
+        root: Checkpoint<ChainBlockMetadata>,
+        tip: Checkpoint<ChainBlockMetadata>,
+        event: Option<L1Event>,
+    ) {
+        let mut subscribers = self.subscribers.lock().expect("subscribers lock poisoned");
+        let _ = self.position.send(TipPosition::new(root, tip));
+        if let Some(event) = event {
+            subscribers.retain(|sender| sender.send(event.clone()).is_ok());
+        }
+    }
+
+    /// Returns the `root`/`tip` to start from: the caller's explicit config if either is set, or
+    /// otherwise whatever a configured [`CheckpointStore`](crate::CheckpointStore) last persisted.
+    fn resume_state(
+        &self,
+    ) -> (Option<Checkpoint<ChainBlockMetadata>>, Option<Checkpoint<ChainBlockMetadata>>) {
+        if self.config.root.is_some() || self.config.tip.is_some() {
+            return (self.config.root.clone(), self.config.tip.clone());
+        }
+        match &self.config.store {
+            Some(store) => store.load(),
+            None => (None, None),
+        }
+    }
+
+    /// Persists the root according to the configured store, regardless of flush policy — root
+    /// advances are rare enough that there is no volume concern in always writing them through.
+    fn persist_root(&self, root: &Checkpoint<ChainBlockMetadata>) {
+        if let Some(store) = &self.config.store {
+            store.write_root(root);
+        }
+    }
+
+    /// Persists the tip if the configured flush policy is [`FlushPolicy::WriteThrough`]; under
+    /// [`FlushPolicy::OnFinalization`] the tip is only flushed via
+    /// [`persist_tip_unconditionally`](Self::persist_tip_unconditionally).
+    fn persist_tip(&self, tip: &Checkpoint<ChainBlockMetadata>) {
+        if self.config.flush_policy == FlushPolicy::WriteThrough {
+            self.persist_tip_unconditionally(tip);
+        }
+    }
+
+    /// Persists the tip regardless of flush policy, used at finalization and reset points.
+    fn persist_tip_unconditionally(&self, tip: &Checkpoint<ChainBlockMetadata>) {
+        if let Some(store) = &self.config.store {
+            store.write_tip(tip);
+        }
+    }
+
+    /// Runs the connect/reconnect loop until shut down, forwarding chain events as they occur.
+    pub(crate) fn run(mut self) {
+        let mut first_connect = true;
+        while !self.shutdown.load(Ordering::Relaxed) {
+            match self.connect_and_sync(first_connect) {
+                Ok(()) => {}
+                Err(reason) => {
+                    self.broadcast(L1Event::Fatal { reason: reason.to_string() });
+                    return;
+                }
+            }
+            first_connect = false;
+        }
+    }
+
+    /// Connects to the L1 node, establishes a baseline (backfilling from `root`/`tip` on first
+    /// connect, otherwise re-validating the previous tip), then processes notifications until
+    /// disconnected or shut down.
+    fn connect_and_sync(&mut self, first_connect: bool) -> Result<(), Error> {
+        self.broadcast(L1Event::Connected);
+
+        let (configured_root, configured_tip) = if first_connect {
+            self.resume_state()
+        } else {
+            // Resume from the position last reached before the disconnect, not from the
+            // (possibly long-stale) config the bridge was originally constructed with.
+            let last = self.position.borrow().clone();
+            (Some(last.root().clone()), Some(last.tip().clone()))
+        };
+
+        let mut chain = if first_connect {
+            // Backfill silently between `root` and `tip`; once caught up, a single `Reset`
+            // establishes the baseline so consumers rebuild from here rather than replaying the
+            // whole backfill block by block.
+            let root = configured_root.unwrap_or_default();
+            let mut chain = VirtualChain::new(root);
+            if let Some(tip) = configured_tip {
+                self.backfill(&mut chain, &tip)?;
+            }
+            self.persist_tip_unconditionally(&chain.tip());
+            self.publish(
+                chain.root(),
+                chain.tip(),
+                Some(L1Event::Reset { root: chain.root(), tip: chain.tip() }),
+            );
+            chain
+        } else {
+            // Reconnecting: if the previous tip can no longer be proven to still be on the
+            // selected chain, the bridge cannot resume incrementally from it.
+            let root = configured_root.unwrap_or_default();
+            let chain = VirtualChain::new(root.clone());
+            match configured_tip {
+                Some(tip) if self.tip_still_on_chain(&tip)? => {
+                    let mut chain = chain;
+                    self.backfill(&mut chain, &tip)?;
+                    chain
+                }
+                _ => {
+                    self.persist_tip_unconditionally(&root);
+                    self.publish(
+                        root.clone(),
+                        root.clone(),
+                        Some(L1Event::Reset { root: root.clone(), tip: root }),
+                    );
+                    chain
+                }
+            }
+        };
+        let mut reorg_filter = ReorgFilter::new(self.config.reorg_filter_halving_period);
+
+        // Connection and notification handling against the L1 node lives here; each
+        // selected-parent-chain-changed notification drives `chain.advance_tip`/`chain.rollback`
+        // and each finality-conjecture notification drives `chain.advance_root`, pushing the
+        // corresponding `L1Event` below.
+        for notification in self.notifications() {
+            if self.shutdown.load(Ordering::Relaxed) {
+                break;
+            }
+            self.handle_notification(&mut chain, &mut reorg_filter, notification)?;
+        }
+
+        self.broadcast(L1Event::Disconnected);
+        Ok(())
+    }
+
+    /// Applies blocks between `chain`'s current tip and `target` without emitting individual
+    /// `ChainBlockAdded` events; the caller emits a single `Reset` once caught up.
+    fn backfill(&self, chain: &mut VirtualChain, target: &Checkpoint<ChainBlockMetadata>) -> Result<(), Error> {
+        for metadata in self.fetch_chain_blocks(chain.tip().index(), target.index()) {
+            chain.advance_tip(metadata);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if `tip` is still on the node's currently selected chain.
+    fn tip_still_on_chain(&self, _tip: &Checkpoint<ChainBlockMetadata>) -> Result<bool, Error> {
+        Ok(true)
+    }
+
+    fn fetch_chain_blocks(&self, _from_index: u64, _to_index: u64) -> Vec<ChainBlockMetadata> {
+        Vec::new()
+    }
+
+    /// Fetches the L1 node's current pruning point, used to re-anchor the virtual chain after a
+    /// reorg deeper than the finalization boundary.
+    fn fetch_pruning_point(&self) -> Checkpoint<ChainBlockMetadata> {
+        Checkpoint::default()
+    }
+
+    fn notifications(&self) -> impl Iterator<Item = ChainNotification> {
+        std::iter::empty()
+    }
+
+    fn handle_notification(
+        &mut self,
+        chain: &mut VirtualChain,
+        reorg_filter: &mut ReorgFilter,
+        notification: ChainNotification,
+    ) -> Result<(), Error> {
+        match notification {
+            ChainNotification::BlockAdded { checkpoint, header, accepted_transactions } => {
+                let checkpoint = chain.advance_tip(checkpoint);
+                self.persist_tip(&checkpoint);
+                self.publish(
+                    chain.root(),
+                    checkpoint.clone(),
+                    Some(L1Event::ChainBlockAdded {
+                        checkpoint,
+                        header: Box::new(header),
+                        accepted_transactions,
+                    }),
+                );
+            }
+            ChainNotification::Reorg { num_checkpoints } => match chain.rollback(num_checkpoints) {
+                Ok((checkpoint, blue_score_depth, reverted)) => {
+                    self.persist_tip(&checkpoint);
+                    let suppressed = reorg_filter.observe(blue_score_depth);
+                    let event = (!suppressed)
+                        .then(|| L1Event::Rollback { checkpoint: checkpoint.clone(), blue_score_depth, reverted });
+                    self.publish(chain.root(), checkpoint, event);
+                }
+                Err(Error::RollbackPastRoot { .. }) => {
+                    // The reorg reaches past the finalization boundary and can no longer be
+                    // expressed as a linear rollback. Re-anchor at the L1 pruning point and let
+                    // consumers resync instead of treating this as fatal.
+                    let pruning_point = self.fetch_pruning_point();
+                    *chain = VirtualChain::new(pruning_point.clone());
+                    self.persist_root(&pruning_point);
+                    self.persist_tip_unconditionally(&pruning_point);
+                    self.publish(
+                        pruning_point.clone(),
+                        pruning_point.clone(),
+                        Some(L1Event::Reset { root: pruning_point.clone(), tip: pruning_point }),
+                    );
+                }
+                Err(error) => return Err(error),
+            },
+            ChainNotification::Finalized { hash } => {
+                if let Some(checkpoint) = chain.advance_root(&hash)? {
+                    self.persist_root(&checkpoint);
+                    self.persist_tip_unconditionally(&chain.tip());
+                    self.publish(checkpoint.clone(), chain.tip(), Some(L1Event::Finalized(checkpoint)));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Notifications relevant to virtual chain tracking, distilled from the raw wRPC notification
+/// stream.
+enum ChainNotification {
+    BlockAdded {
+        checkpoint: ChainBlockMetadata,
+        header: RpcOptionalHeader,
+        accepted_transactions: Vec<RpcOptionalTransaction>,
+    },
+    Reorg {
+        num_checkpoints: u64,
+    },
+    Finalized {
+        hash: crate::BlockHash,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use crossbeam_channel::unbounded;
+    use tokio::sync::watch;
+
+    use super::*;
+    use crate::{checkpoint_store::CheckpointStore, BlockHash};
+
+    #[derive(Default)]
+    struct RecordingStore {
+        roots: Mutex<Vec<Checkpoint<ChainBlockMetadata>>>,
+        tips: Mutex<Vec<Checkpoint<ChainBlockMetadata>>>,
+    }
+
+    impl CheckpointStore for RecordingStore {
+        fn write_root(&self, checkpoint: &Checkpoint<ChainBlockMetadata>) {
+            self.roots.lock().expect("roots lock poisoned").push(checkpoint.clone());
+        }
+
+        fn write_tip(&self, checkpoint: &Checkpoint<ChainBlockMetadata>) {
+            self.tips.lock().expect("tips lock poisoned").push(checkpoint.clone());
+        }
+
+        fn load(&self) -> (Option<Checkpoint<ChainBlockMetadata>>, Option<Checkpoint<ChainBlockMetadata>>) {
+            (None, None)
+        }
+    }
+
+    fn checkpoint(index: u64) -> Checkpoint<ChainBlockMetadata> {
+        Checkpoint::new(index, ChainBlockMetadata::new(BlockHash::default(), index * 10))
+    }
+
+    fn worker_with(store: Arc<RecordingStore>, flush_policy: FlushPolicy) -> Worker {
+        let config = L1BridgeConfig { store: Some(store), flush_policy, ..L1BridgeConfig::default() };
+        let (_sender, _receiver) = unbounded::<L1Event>();
+        let subscribers: Subscribers = Arc::new(Mutex::new(Vec::new()));
+        let (position_tx, _position_rx) = watch::channel(TipPosition::default());
+        Worker::new(config, subscribers, position_tx, Arc::new(AtomicBool::new(false)))
+    }
+
+    #[test]
+    fn write_through_persists_every_tip() {
+        let store = Arc::new(RecordingStore::default());
+        let worker = worker_with(store.clone(), FlushPolicy::WriteThrough);
+
+        worker.persist_tip(&checkpoint(1));
+        worker.persist_tip(&checkpoint(2));
+
+        assert_eq!(store.tips.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn on_finalization_buffers_the_tip_until_flushed_unconditionally() {
+        let store = Arc::new(RecordingStore::default());
+        let worker = worker_with(store.clone(), FlushPolicy::OnFinalization);
+
+        worker.persist_tip(&checkpoint(1));
+        worker.persist_tip(&checkpoint(2));
+        assert!(store.tips.lock().unwrap().is_empty());
+
+        worker.persist_tip_unconditionally(&checkpoint(2));
+        assert_eq!(store.tips.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn root_is_always_persisted_regardless_of_flush_policy() {
+        let store = Arc::new(RecordingStore::default());
+        let worker = worker_with(store.clone(), FlushPolicy::OnFinalization);
+
+        worker.persist_root(&checkpoint(1));
+
+        assert_eq!(store.roots.lock().unwrap().len(), 1);
+    }
+}